@@ -3,24 +3,73 @@
 
 #![allow(clippy::disallowed_macros)]
 
-use crate::utils::{GitReference, GitRemote};
+use crate::utils::{self, Backend, Forge, GitAuth, GitReference, GitRemote, Oid};
 use ethers_contract::MultiAbigen;
 pub use foundry_config::Config;
 use std::{
+    cell::RefCell,
+    fmt,
     path::{Path, PathBuf},
     process::{Command, Stdio},
 };
-use tempfile::{tempdir, TempDir};
+use tempfile::TempDir;
 use tracing::trace;
 pub use url::Url;
 
 pub mod utils;
 
+/// Where `Binder` resolves its project from, and how it runs commands against it.
+///
+/// Implemented by [`SourceLocation`] for real clones/local paths. Tests provide their own
+/// implementation (a `MockSource` fixture) to exercise `Binder::generate`'s config-merging,
+/// command-execution ordering, artifact-dir override and `write_to_module` path-selection logic
+/// without ever cloning a repository or invoking the solidity compiler.
+pub trait Source {
+    /// Returns the path to the project, cloning or copying it into place first if needed.
+    fn get(&self) -> eyre::Result<PathBuf>;
+
+    /// Resolves this source to a content fingerprint, without paying for a full clone/copy.
+    fn fingerprint(&self) -> eyre::Result<String>;
+
+    /// The local path backing this source, if it is not a remote repository. Used to emit
+    /// `cargo:rerun-if-changed` directives.
+    fn local_path(&self) -> Option<&Path> {
+        None
+    }
+
+    /// Runs `args` as a command in `root`.
+    ///
+    /// The default implementation shells out for real; a test source can override this to
+    /// record the command instead of actually running it.
+    fn run_command(&self, root: &Path, mut args: Vec<String>) -> eyre::Result<()> {
+        eyre::ensure!(!args.is_empty(), "Command can't be empty");
+
+        let mut cmd = Command::new(args.remove(0));
+        cmd.current_dir(root).args(args).stderr(Stdio::inherit()).stdout(Stdio::inherit());
+        trace!("Executing command {:?}", cmd);
+        cmd.output()?;
+        Ok(())
+    }
+}
+
+impl Source for SourceLocation {
+    fn get(&self) -> eyre::Result<PathBuf> {
+        SourceLocation::get(self)
+    }
+
+    fn fingerprint(&self) -> eyre::Result<String> {
+        SourceLocation::fingerprint(self)
+    }
+
+    fn local_path(&self) -> Option<&Path> {
+        SourceLocation::local_path(self)
+    }
+}
+
 /// Contains all the options to configure the gen process
-#[derive(Debug)]
 pub struct Binder {
     /// Where to find the project
-    location: SourceLocation,
+    location: Box<dyn Source>,
     /// Whether to include the bytecode in the bindings to be able to deploy them
     deployable: bool,
     /// Contains the directory where the artifacts should be written, if `None`, the artifacts will
@@ -34,6 +83,18 @@ pub struct Binder {
     bindings: Option<PathBuf>,
 }
 
+impl fmt::Debug for Binder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binder")
+            .field("deployable", &self.deployable)
+            .field("keep_artifacts", &self.keep_artifacts)
+            .field("commands", &self.commands)
+            .field("config", &self.config)
+            .field("bindings", &self.bindings)
+            .finish_non_exhaustive()
+    }
+}
+
 // == impl Binder ==
 
 impl Binder {
@@ -61,7 +122,23 @@ impl Binder {
     /// ```
     pub fn new(location: impl Into<SourceLocation>) -> Self {
         Self {
-            location: location.into(),
+            location: Box::new(location.into()),
+            deployable: true,
+            keep_artifacts: None,
+            commands: vec![],
+            config: None,
+            bindings: None,
+        }
+    }
+
+    /// Like [`Binder::new`] but takes the [`Source`] directly instead of requiring a concrete
+    /// [`SourceLocation`], so tests can swap in a fixture (e.g. a mock that points at a fixture
+    /// directory and records commands instead of running them) without cloning or compiling
+    /// anything for real.
+    #[cfg(test)]
+    fn with_source(source: Box<dyn Source>) -> Self {
+        Self {
+            location: source,
             deployable: true,
             keep_artifacts: None,
             commands: vec![],
@@ -145,7 +222,46 @@ impl Binder {
     }
 
     /// Generates the bindings
+    ///
+    /// Since this is meant to run inside `build.rs`, this resolves the source to a
+    /// [`Fingerprint`] first and skips the clone/compile/codegen entirely if nothing relevant
+    /// has changed since the last run - see [`Fingerprint`] for details.
     pub fn generate(&self) -> eyre::Result<()> {
+        let module = self.module_path();
+
+        if let Some(local) = self.location.local_path() {
+            println!("cargo:rerun-if-changed={}", local.display());
+        }
+        println!("cargo:rerun-if-changed={}", Fingerprint::marker_path(&module).display());
+
+        let fingerprint = Fingerprint::resolve(self)?;
+        if Fingerprint::read(&module).as_ref() == Some(&fingerprint) {
+            trace!("Fingerprint unchanged, skipping clone/compile/codegen");
+            return Ok(());
+        }
+
+        self.generate_bindings(&module)?;
+        fingerprint.write(&module)?;
+        Ok(())
+    }
+
+    /// Where the generated bindings module is written: the path set via [`Binder::bindings`], or
+    /// `src/contracts` if none was configured.
+    ///
+    /// Split out of [`Binder::generate`] so this path-selection logic can be tested directly.
+    fn module_path(&self) -> PathBuf {
+        self.bindings.clone().unwrap_or_else(|| "src/contracts".into())
+    }
+
+    /// Resolves the source, merges it with the configured [`Config`], runs the configured
+    /// commands against the checked-out project (in order), and applies the `keep_artifacts`
+    /// override.
+    ///
+    /// Split out of [`Binder::generate_bindings`] so this part - config-merging,
+    /// command-execution ordering, and the artifact-dir override - can be exercised with a mock
+    /// [`Source`] in tests, without needing a real `forge` toolchain to actually compile
+    /// anything.
+    fn prepare_project(&self) -> eyre::Result<foundry_config::Project> {
         let project = self.location.get()?;
 
         let config = if let Some(mut config) = self.config.clone() {
@@ -155,17 +271,9 @@ impl Binder {
             foundry_config::load_config_with_root(Some(project))
         };
 
-        // run all commands
-        for mut args in self.commands.clone() {
-            eyre::ensure!(!args.is_empty(), "Command can't be empty");
-
-            let mut cmd = Command::new(args.remove(0));
-            cmd.current_dir(&config.__root.0)
-                .args(args)
-                .stderr(Stdio::inherit())
-                .stdout(Stdio::inherit());
-            trace!("Executing command {:?}", cmd);
-            cmd.output()?;
+        // run all commands, in order
+        for args in self.commands.clone() {
+            self.location.run_command(&config.__root.0, args)?;
         }
 
         let mut project = config.project()?;
@@ -176,6 +284,12 @@ impl Binder {
             project.paths.artifacts = keep_artifacts;
         }
 
+        Ok(project)
+    }
+
+    fn generate_bindings(&self, module: &Path) -> eyre::Result<()> {
+        let project = self.prepare_project()?;
+
         let compiled = project.compile()?;
         if compiled.has_compiler_errors() {
             eyre::bail!("Compiled with errors:\n{compiled}");
@@ -185,14 +299,59 @@ impl Binder {
         let bindings = MultiAbigen::from_json_files(project.artifacts_path())?.build()?;
         trace!("Generated bindings");
 
-        trace!("Writing bindings to `src/contracts`");
-        let module = self.bindings.clone().unwrap_or_else(|| "src/contracts".into());
+        trace!("Writing bindings to `{}`", module.display());
         bindings.write_to_module(module, false)?;
 
         Ok(())
     }
 }
 
+/// A content-addressed cache marker written alongside the generated bindings module.
+///
+/// It records the resolved source revision (a commit oid for remote repos, a directory hash
+/// for local ones) together with a hash of the [`Config`], `commands` and `deployable` flag, so
+/// a rerun of [`Binder::generate`] with unchanged inputs can skip cloning, running commands,
+/// compiling and regenerating bindings entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fingerprint {
+    source: String,
+    settings: u64,
+}
+
+// === impl Fingerprint ===
+
+impl Fingerprint {
+    fn resolve(binder: &Binder) -> eyre::Result<Self> {
+        use std::hash::{Hash, Hasher};
+
+        let source = binder.location.fingerprint()?;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        format!("{:?}", binder.config).hash(&mut hasher);
+        binder.commands.hash(&mut hasher);
+        binder.deployable.hash(&mut hasher);
+
+        Ok(Self { source, settings: hasher.finish() })
+    }
+
+    fn marker_path(module: &Path) -> PathBuf {
+        module.join(".binder-fingerprint")
+    }
+
+    fn read(module: &Path) -> Option<Self> {
+        let raw = std::fs::read_to_string(Self::marker_path(module)).ok()?;
+        let (source, settings) = raw.split_once(':')?;
+        Some(Self { source: source.to_string(), settings: settings.parse().ok()? })
+    }
+
+    fn write(&self, module: &Path) -> eyre::Result<()> {
+        let marker = Self::marker_path(module);
+        std::fs::create_dir_all(module)?;
+        std::fs::write(marker, format!("{}:{}", self.source, self.settings))?;
+        Ok(())
+    }
+}
+
 /// Where to find the source project
 #[derive(Debug)]
 pub enum SourceLocation {
@@ -216,6 +375,59 @@ impl SourceLocation {
         };
         Ok(path)
     }
+
+    /// Resolves this source to a content fingerprint, without paying for a full clone or copy:
+    /// the concrete commit for a remote repository, or a hash of the local directory tree.
+    pub fn fingerprint(&self) -> eyre::Result<String> {
+        match self {
+            SourceLocation::Local(p) => hash_dir(p),
+            SourceLocation::Remote(r) => r.resolve_revision(),
+        }
+    }
+
+    /// The local path backing this source, if it is not a remote repository.
+    ///
+    /// Used to emit `cargo:rerun-if-changed` directives so Cargo only reinvokes the build
+    /// script when the local sources actually change.
+    fn local_path(&self) -> Option<&Path> {
+        match self {
+            SourceLocation::Local(p) => Some(p),
+            SourceLocation::Remote(_) => None,
+        }
+    }
+}
+
+/// Computes a cheap fingerprint for a local directory tree by hashing the relative path, size
+/// and modification time of every file under it.
+fn hash_dir(dir: &Path) -> eyre::Result<String> {
+    use std::hash::{Hash, Hasher};
+
+    fn collect(dir: &Path, out: &mut Vec<PathBuf>) -> eyre::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                collect(&path, out)?;
+            } else {
+                out.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    collect(dir, &mut files)?;
+    files.sort();
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for path in files {
+        let meta = std::fs::metadata(&path)?;
+        path.strip_prefix(dir).unwrap_or(&path).hash(&mut hasher);
+        meta.len().hash(&mut hasher);
+        if let Ok(modified) = meta.modified() {
+            modified.hash(&mut hasher);
+        }
+    }
+    Ok(format!("{:x}", hasher.finish()))
 }
 
 impl From<Repository> for SourceLocation {
@@ -237,20 +449,27 @@ impl From<Url> for SourceLocation {
 }
 
 impl<'a> From<&'a str> for SourceLocation {
+    /// Disambiguates `path` between a forge shorthand (`github:aave/aave-v3-core`, bare
+    /// `owner/repo`, ...) and a local path, routing shorthands through
+    /// [`utils::parse_shorthand`] and falling back to the existing local-path behavior for
+    /// everything else.
     fn from(path: &'a str) -> Self {
+        if let Some((forge, url)) = utils::parse_shorthand(path) {
+            return RepositoryBuilder::new(url).forge(forge).into();
+        }
         SourceLocation::Local(path.into())
     }
 }
 
 impl<'a> From<&'a String> for SourceLocation {
     fn from(path: &'a String) -> Self {
-        SourceLocation::Local(path.into())
+        path.as_str().into()
     }
 }
 
 impl From<String> for SourceLocation {
     fn from(path: String) -> Self {
-        SourceLocation::Local(path.into())
+        path.as_str().into()
     }
 }
 
@@ -279,31 +498,75 @@ pub struct Repository {
     pub db_path: Option<PathBuf>,
     /// Where to clone into
     pub dest: RepositoryDestination,
+    /// Whether to recursively init and update git submodules after checkout
+    pub submodules: bool,
+    /// Which VCS backend to check this repository out with
+    pub backend: Backend,
+    /// The forge this repository was resolved from, if it was constructed from a shorthand
+    /// spec like `github:aave/aave-v3-core`
+    pub forge: Option<Forge>,
+    /// Caches the oid resolved by the last call to [`Repository::resolve_revision`], so a
+    /// subsequent [`Repository::checkout`] in the same build reuses it instead of fetching and
+    /// resolving the reference all over again.
+    resolved_oid: RefCell<Option<Oid>>,
 }
 
 // === impl Repository ===
 
 impl Repository {
     pub fn checkout(&self) -> eyre::Result<()> {
-        fn copy_to(
-            repo: &GitRemote,
-            rev: &GitReference,
-            db_path: &Path,
-            dest: &Path,
-        ) -> eyre::Result<()> {
-            let (local, oid) = repo.checkout(db_path, rev, None)?;
-            local.copy_to(oid, dest)?;
-            Ok(())
+        let db = self.db_path();
+
+        match &self.backend {
+            Backend::Git => {
+                let locked = *self.resolved_oid.borrow();
+                let (local, oid) = self.repo.checkout(&db, &self.rev, locked)?;
+                local.copy_to(oid, self.dest.as_ref(), self.submodules)?;
+                Ok(())
+            }
+            Backend::Mercurial => utils::hg_checkout(self.repo.url(), &self.rev, &db, self.dest.as_ref()),
+            Backend::Unknown(name) => {
+                eyre::bail!("unsupported vcs backend `{name}`; only git and mercurial are supported")
+            }
         }
+    }
 
-        if let Some(ref db) = self.db_path {
-            copy_to(&self.repo, &self.rev, db, self.dest.as_ref())
-        } else {
-            let tmp = tempdir()?;
-            let db = tmp.path().join(self.dest.as_ref().file_name().unwrap());
-            copy_to(&self.repo, &self.rev, &db, self.dest.as_ref())
+    /// Resolves the configured branch/tag/rev to a concrete revision, without materializing a
+    /// full working tree.
+    ///
+    /// This is the cheap half of [`Repository::checkout`] (no submodule checkout, no working
+    /// tree copy) and is used to compute the incremental-build fingerprint in
+    /// [`Binder::generate`] without paying for a full clone on every build. The resolved oid is
+    /// cached so that if the fingerprint turns out to be stale and `checkout` runs right after,
+    /// it reuses this resolution instead of fetching the remote a second time.
+    pub fn resolve_revision(&self) -> eyre::Result<String> {
+        match &self.backend {
+            Backend::Git => {
+                let db = self.db_path();
+                let (_, oid) = self.repo.checkout(&db, &self.rev, None)?;
+                *self.resolved_oid.borrow_mut() = Some(oid);
+                Ok(oid.to_string())
+            }
+            Backend::Mercurial => utils::hg_resolve(self.repo.url(), &self.rev),
+            Backend::Unknown(name) => {
+                eyre::bail!("unsupported vcs backend `{name}`; only git and mercurial are supported")
+            }
         }
     }
+
+    /// Resolves where to keep the repo's git database, defaulting to a location under the
+    /// system temp dir that's stable (keyed by the clone url) rather than a fresh scratch dir
+    /// per call. This way repeat builds incrementally fetch into an existing local database
+    /// instead of paying for a full fetch from scratch every time.
+    fn db_path(&self) -> PathBuf {
+        self.db_path.clone().unwrap_or_else(|| {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            self.repo.url().as_str().hash(&mut hasher);
+            let name = self.repo.url().path_segments().and_then(|s| s.last()).unwrap_or("repo");
+            std::env::temp_dir().join("foundry-binder-db").join(format!("{name}-{:x}", hasher.finish()))
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -313,13 +576,44 @@ pub struct RepositoryBuilder {
     rev: GitReference,
     dest: Option<PathBuf>,
     db_path: Option<PathBuf>,
+    submodules: bool,
+    backend: Backend,
+    forge: Option<Forge>,
 }
 
 // === impl RepositoryBuilder ===
 
 impl RepositoryBuilder {
     pub fn new(url: Url) -> Self {
-        Self { repo: GitRemote::new(url), rev: Default::default(), dest: None, db_path: None }
+        Self {
+            backend: Backend::detect(&url),
+            repo: GitRemote::new(url),
+            rev: Default::default(),
+            dest: None,
+            db_path: None,
+            submodules: true,
+            forge: None,
+        }
+    }
+
+    /// Sets which VCS backend to check this repository out with.
+    ///
+    /// This overrides whatever [`Backend::detect`] inferred from the url, e.g. when a
+    /// mercurial repository is hosted behind a url that doesn't carry a `hg+` scheme.
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Records which forge this repository came from.
+    ///
+    /// This is set automatically when constructing a [`SourceLocation`] from a shorthand spec
+    /// like `github:aave/aave-v3-core`; set it manually if you built the clone url yourself. The
+    /// forge is only stored on [`Repository::forge`]: checkout, auth and branch resolution do
+    /// not read it.
+    pub fn forge(mut self, forge: Forge) -> Self {
+        self.forge = Some(forge);
+        self
     }
 
     /// Specify the branch to checkout
@@ -354,8 +648,35 @@ impl RepositoryBuilder {
         self
     }
 
+    /// Configures HTTP basic auth credentials for fetching from a private repository.
+    pub fn basic_auth(mut self, username: impl Into<String>, token: impl Into<String>) -> Self {
+        self.repo = self.repo.with_auth(GitAuth::Basic { username: username.into(), token: token.into() });
+        self
+    }
+
+    /// Configures credentials that are read lazily from the given environment variable at
+    /// `generate()` time, so the token itself never ends up baked into the build artifact.
+    ///
+    /// If the variable is unset, `checkout()` will return an error rather than silently
+    /// falling back to anonymous access, since that would otherwise hide a misconfigured
+    /// build environment. Repositories with no credentials configured at all continue to use
+    /// anonymous access.
+    pub fn token_from_env(mut self, var: impl Into<String>) -> Self {
+        self.repo = self.repo.with_auth(GitAuth::TokenEnv { var: var.into() });
+        self
+    }
+
+    /// Whether to recursively init and update git submodules after checkout (default: `true`).
+    ///
+    /// This is the equivalent of `git submodule update --init --recursive`, pinning each
+    /// submodule to the gitlink commit recorded by its parent so checkouts stay reproducible.
+    pub fn submodules(mut self, submodules: bool) -> Self {
+        self.submodules = submodules;
+        self
+    }
+
     pub fn build(self) -> Repository {
-        let RepositoryBuilder { repo, rev, dest, db_path } = self;
+        let RepositoryBuilder { repo, rev, dest, db_path, submodules, backend, forge } = self;
         let dest = if let Some(dest) = dest {
             RepositoryDestination::Path(dest)
         } else {
@@ -364,13 +685,14 @@ impl RepositoryBuilder {
                 tempfile::Builder::new().prefix(name).tempdir().expect("Failed to create tempdir");
             RepositoryDestination::Temp(dir)
         };
-        Repository { dest, repo, rev, db_path }
+        Repository { dest, repo, rev, db_path, submodules, backend, forge, resolved_oid: RefCell::new(None) }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
 
     #[test]
     #[ignore]
@@ -382,4 +704,144 @@ mod tests {
 
         repo.checkout().unwrap();
     }
+
+    /// A [`Source`] fixture that points at a directory already on disk and records which
+    /// commands would have run against it, instead of actually running them. Lets the rest of
+    /// `Binder` be unit-tested without a live network clone or a real `forge` toolchain.
+    #[derive(Debug)]
+    struct MockSource {
+        project: PathBuf,
+        fingerprint: String,
+        commands: RefCell<Vec<Vec<String>>>,
+    }
+
+    impl MockSource {
+        fn new(project: impl Into<PathBuf>, fingerprint: impl Into<String>) -> Self {
+            Self { project: project.into(), fingerprint: fingerprint.into(), commands: RefCell::new(vec![]) }
+        }
+    }
+
+    impl Source for MockSource {
+        fn get(&self) -> eyre::Result<PathBuf> {
+            Ok(self.project.clone())
+        }
+
+        fn fingerprint(&self) -> eyre::Result<String> {
+            Ok(self.fingerprint.clone())
+        }
+
+        fn local_path(&self) -> Option<&Path> {
+            Some(&self.project)
+        }
+
+        fn run_command(&self, _root: &Path, args: Vec<String>) -> eyre::Result<()> {
+            self.commands.borrow_mut().push(args);
+            Ok(())
+        }
+    }
+
+    /// Lets a [`MockSource`] be shared between a [`Binder`] (which takes ownership of its
+    /// `Box<dyn Source>`) and the test asserting on it afterwards.
+    impl Source for std::rc::Rc<MockSource> {
+        fn get(&self) -> eyre::Result<PathBuf> {
+            self.as_ref().get()
+        }
+
+        fn fingerprint(&self) -> eyre::Result<String> {
+            self.as_ref().fingerprint()
+        }
+
+        fn local_path(&self) -> Option<&Path> {
+            self.as_ref().local_path()
+        }
+
+        fn run_command(&self, root: &Path, args: Vec<String>) -> eyre::Result<()> {
+            self.as_ref().run_command(root, args)
+        }
+    }
+
+    #[test]
+    fn mock_source_resolves_fixture_path_and_fingerprint() {
+        let source = MockSource::new("./assets/fixture-project", "deadbeef");
+
+        assert_eq!(source.get().unwrap(), PathBuf::from("./assets/fixture-project"));
+        assert_eq!(source.fingerprint().unwrap(), "deadbeef");
+        assert_eq!(source.local_path(), Some(Path::new("./assets/fixture-project")));
+    }
+
+    #[test]
+    fn mock_source_records_commands_in_order_without_running_them() {
+        let source = MockSource::new("./assets/fixture-project", "deadbeef");
+        let root = Path::new("./assets/fixture-project");
+
+        source.run_command(root, vec!["yarn".into(), "install".into()]).unwrap();
+        source.run_command(root, vec!["forge".into(), "build".into()]).unwrap();
+
+        assert_eq!(
+            source.commands.into_inner(),
+            vec![
+                vec!["yarn".to_string(), "install".to_string()],
+                vec!["forge".to_string(), "build".to_string()],
+            ]
+        );
+    }
+
+    /// Builds a minimal on-disk foundry project (just a `foundry.toml`) that
+    /// [`foundry_config::load_config_with_root`]/[`Config::project`] can resolve against, so
+    /// `Binder::prepare_project` can be exercised end to end without a real clone.
+    fn fixture_project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("foundry.toml"), "[profile.default]\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn prepare_project_runs_commands_in_order_against_mock_source() {
+        let fixture = fixture_project();
+        let source = std::rc::Rc::new(MockSource::new(fixture.path(), "deadbeef"));
+
+        let binder = Binder::with_source(Box::new(source.clone()))
+            .command(["yarn", "install"])
+            .command(["forge", "build"]);
+
+        binder.prepare_project().unwrap();
+
+        assert_eq!(
+            source.commands.borrow().clone(),
+            vec![
+                vec!["yarn".to_string(), "install".to_string()],
+                vec!["forge".to_string(), "build".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn prepare_project_overrides_artifacts_dir() {
+        let fixture = fixture_project();
+        let artifacts = tempfile::tempdir().unwrap();
+        let source = MockSource::new(fixture.path(), "deadbeef");
+
+        let binder =
+            Binder::with_source(Box::new(source)).keep_artifacts(artifacts.path().to_path_buf());
+
+        let project = binder.prepare_project().unwrap();
+
+        assert_eq!(project.paths.artifacts, artifacts.path());
+    }
+
+    #[test]
+    fn module_path_defaults_to_src_contracts() {
+        let source = MockSource::new("./assets/fixture-project", "deadbeef");
+        let binder = Binder::with_source(Box::new(source));
+
+        assert_eq!(binder.module_path(), PathBuf::from("src/contracts"));
+    }
+
+    #[test]
+    fn module_path_uses_configured_bindings_override() {
+        let source = MockSource::new("./assets/fixture-project", "deadbeef");
+        let binder = Binder::with_source(Box::new(source)).bindings("gen/bindings");
+
+        assert_eq!(binder.module_path(), PathBuf::from("gen/bindings"));
+    }
 }
@@ -0,0 +1,659 @@
+//! Low-level VCS plumbing used to resolve and check out [`Repository`](crate::Repository)
+//! sources.
+//!
+//! This is intentionally narrow in scope: it only supports the handful of operations
+//! `Binder` needs (resolve a reference to a commit, check out a worktree, copy it
+//! somewhere stable) rather than being a general purpose git or mercurial wrapper.
+
+use eyre::{eyre, Context, Result};
+pub use git2::Oid;
+use git2::{Repository as Git2Repository, Submodule};
+use std::{
+    collections::HashSet,
+    fmt,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, trace};
+use url::Url;
+
+/// A reference to a specific revision in a [`GitRemote`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitReference {
+    /// From a branch.
+    Branch(String),
+    /// From a tag.
+    Tag(String),
+    /// From a specific revision or short sha.
+    Rev(String),
+    /// The repo's default branch, whatever it resolves to (usually `HEAD`).
+    DefaultBranch,
+}
+
+impl Default for GitReference {
+    fn default() -> Self {
+        GitReference::DefaultBranch
+    }
+}
+
+impl GitReference {
+    /// The refspec to fetch for this reference.
+    fn refspec(&self) -> String {
+        match self {
+            GitReference::Branch(s) => format!("refs/heads/{s}:refs/remotes/origin/{s}"),
+            GitReference::Tag(s) => format!("refs/tags/{s}:refs/remotes/origin/tags/{s}"),
+            // We can't know the ref ahead of time for a bare revision, so just grab everything.
+            GitReference::Rev(_) | GitReference::DefaultBranch => {
+                "refs/heads/*:refs/remotes/origin/*".into()
+            }
+        }
+    }
+
+    /// Resolves this reference to an [`Oid`] in the given repository.
+    ///
+    /// The repository is expected to have already fetched the relevant refs via
+    /// [`GitReference::refspec`].
+    fn resolve(&self, repo: &Git2Repository) -> Result<Oid> {
+        let oid = match self {
+            GitReference::Branch(s) => {
+                let name = format!("origin/{s}");
+                repo.find_branch(&name, git2::BranchType::Remote)
+                    .with_context(|| format!("failed to find branch `{s}`"))?
+                    .get()
+                    .target()
+                    .ok_or_else(|| eyre!("branch `{s}` has no target"))?
+            }
+            GitReference::Tag(s) => {
+                let name = format!("refs/remotes/origin/tags/{s}");
+                repo.find_reference(&name)
+                    .with_context(|| format!("failed to find tag `{s}`"))?
+                    .peel_to_commit()?
+                    .id()
+            }
+            GitReference::Rev(s) => {
+                let obj = repo.revparse_single(s).with_context(|| format!("failed to find rev `{s}`"))?;
+                obj.peel_to_commit()?.id()
+            }
+            GitReference::DefaultBranch => {
+                let head = repo.find_reference("refs/remotes/origin/HEAD").or_else(|_| {
+                    repo.find_reference("FETCH_HEAD")
+                })?;
+                head.peel_to_commit()?.id()
+            }
+        };
+        Ok(oid)
+    }
+}
+
+impl fmt::Display for GitReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitReference::Branch(s) => write!(f, "branch {s}"),
+            GitReference::Tag(s) => write!(f, "tag {s}"),
+            GitReference::Rev(s) => write!(f, "rev {s}"),
+            GitReference::DefaultBranch => write!(f, "the default branch"),
+        }
+    }
+}
+
+/// Credentials to present to the transport when fetching from a [`GitRemote`].
+#[derive(Clone)]
+pub enum GitAuth {
+    /// No credentials; anonymous access.
+    None,
+    /// HTTP basic auth with a fixed username/token pair.
+    Basic { username: String, token: String },
+    /// HTTP basic auth whose token is read from an environment variable lazily, at fetch time,
+    /// so the secret is never baked into a cached [`Config`](crate::Config) or build artifact.
+    TokenEnv { var: String },
+}
+
+impl fmt::Debug for GitAuth {
+    /// Redacts the token/password so `{:?}`-printing a [`GitRemote`]/[`Repository`](crate::Repository)
+    /// (all of which derive `Debug`) never leaks a credential.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitAuth::None => write!(f, "None"),
+            GitAuth::Basic { username, .. } => {
+                f.debug_struct("Basic").field("username", username).field("token", &"<redacted>").finish()
+            }
+            GitAuth::TokenEnv { var } => f.debug_struct("TokenEnv").field("var", var).finish(),
+        }
+    }
+}
+
+impl Default for GitAuth {
+    fn default() -> Self {
+        GitAuth::None
+    }
+}
+
+impl GitAuth {
+    /// Resolves this into a concrete `(username, password)` pair, if any credentials are
+    /// configured.
+    fn resolve(&self) -> Result<Option<(String, String)>> {
+        match self {
+            GitAuth::None => Ok(None),
+            GitAuth::Basic { username, token } => Ok(Some((username.clone(), token.clone()))),
+            GitAuth::TokenEnv { var } => {
+                let token = std::env::var(var)
+                    .with_context(|| format!("env var `{var}` is not set"))?;
+                Ok(Some((token, String::new())))
+            }
+        }
+    }
+}
+
+/// A remote git repository, referenced by its clone [`Url`].
+#[derive(Clone, Debug)]
+pub struct GitRemote {
+    url: Url,
+    auth: GitAuth,
+}
+
+// === impl GitRemote ===
+
+impl GitRemote {
+    pub fn new(url: Url) -> Self {
+        Self { url, auth: GitAuth::default() }
+    }
+
+    /// The clone url of this remote.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Sets the credentials to use when fetching from this remote.
+    #[must_use]
+    pub fn with_auth(mut self, auth: GitAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Opens (or creates) a bare git database at `into`, fetches `reference` from this remote
+    /// and resolves it to a concrete commit.
+    ///
+    /// Returns the opened [`GitDatabase`] together with the resolved [`Oid`] so callers can pin
+    /// subsequent operations (like submodule checkout) to a reproducible commit.
+    pub fn checkout(
+        &self,
+        into: &Path,
+        reference: &GitReference,
+        locked_rev: Option<Oid>,
+    ) -> Result<(GitDatabase, Oid)> {
+        let repo = match Git2Repository::open_bare(into) {
+            Ok(repo) => repo,
+            Err(..) => {
+                let _ = std::fs::remove_dir_all(into);
+                std::fs::create_dir_all(into)?;
+                Git2Repository::init_bare(into)?
+            }
+        };
+
+        // If we already resolved this reference to a commit (e.g. a prior `resolve_revision`
+        // call in the same build) and that commit is already present in the local database,
+        // we can skip fetching the remote again.
+        let already_have = locked_rev.is_some_and(|oid| repo.find_commit(oid).is_ok());
+
+        if !already_have {
+            fetch(&repo, self.url.as_str(), &reference.refspec(), &self.auth)
+                .with_context(|| format!("failed to fetch `{}`", self.url))?;
+        }
+
+        let oid = match locked_rev {
+            Some(oid) => oid,
+            None => reference
+                .resolve(&repo)
+                .with_context(|| format!("failed to find {reference} for `{}`", self.url))?,
+        };
+
+        Ok((GitDatabase { remote: self.clone(), repo }, oid))
+    }
+}
+
+/// An opened, fetched git database that a working tree can be checked out from.
+pub struct GitDatabase {
+    remote: GitRemote,
+    repo: Git2Repository,
+}
+
+// === impl GitDatabase ===
+
+impl GitDatabase {
+    /// Checks out `oid` into `dest`.
+    ///
+    /// If `submodules` is `true`, this recursively initializes and updates submodules so the
+    /// destination tree matches what a `git submodule update --init --recursive` would produce.
+    pub fn copy_to(&self, oid: Oid, dest: &Path, submodules: bool) -> Result<GitCheckout<'_>> {
+        let _ = std::fs::remove_dir_all(dest);
+        std::fs::create_dir_all(dest)?;
+
+        let checkout = GitCheckout::new(self, oid, dest)?;
+        checkout.run(submodules)?;
+        Ok(checkout)
+    }
+}
+
+/// A single checked-out worktree, pinned to a commit.
+pub struct GitCheckout<'a> {
+    database: &'a GitDatabase,
+    oid: Oid,
+    location: PathBuf,
+}
+
+// === impl GitCheckout ===
+
+impl<'a> GitCheckout<'a> {
+    fn new(database: &'a GitDatabase, oid: Oid, location: &Path) -> Result<Self> {
+        Ok(Self { database, oid, location: location.to_path_buf() })
+    }
+
+    fn run(&self, submodules: bool) -> Result<()> {
+        self.checkout_tree()?;
+        if submodules {
+            update_submodules(&self.database.remote, &self.location, &mut HashSet::new())?;
+        }
+        Ok(())
+    }
+
+    fn checkout_tree(&self) -> Result<()> {
+        // Clone locally from the bare db rather than re-resolving `oid` against a brand-new,
+        // empty worktree repo. `git2` (like the `git` CLI) shares the object database via
+        // hardlinks/alternates for local clones instead of copying every object, so this stays
+        // cheap while actually giving the worktree access to the objects it needs.
+        let worktree = git2::build::RepoBuilder::new()
+            .clone(&self.database.repo.path().to_string_lossy(), &self.location)
+            .with_context(|| {
+                format!(
+                    "failed to clone `{}` into `{}`",
+                    self.database.repo.path().display(),
+                    self.location.display()
+                )
+            })?;
+
+        // The clone above points `origin` at the local bare db path; repoint it at the real
+        // remote so the checked-out tree reflects where it actually came from.
+        worktree.remote_set_url("origin", self.database.remote.url().as_str())?;
+
+        let object = worktree.find_object(self.oid, None)?;
+
+        let mut checkout_opts = git2::build::CheckoutBuilder::new();
+        checkout_opts.force();
+
+        worktree.set_head_detached(object.id())?;
+        worktree.checkout_head(Some(&mut checkout_opts))?;
+
+        Ok(())
+    }
+}
+
+/// Recursively initializes and updates submodules under `working_dir`, pinning each to the
+/// gitlink commit recorded by its parent, and resolving relative submodule URLs against
+/// `parent_remote`.
+///
+/// `seen` records every working directory already walked, so none is walked twice. Each level
+/// of the walk is a subdirectory of the one above it, so a submodule that (directly or
+/// transitively) includes its own repository is still checked out again one level deeper.
+fn update_submodules(
+    parent_remote: &GitRemote,
+    working_dir: &Path,
+    seen: &mut HashSet<PathBuf>,
+) -> Result<()> {
+    if !seen.insert(working_dir.to_path_buf()) {
+        return Ok(());
+    }
+
+    let repo = match Git2Repository::open(working_dir) {
+        Ok(repo) => repo,
+        Err(_) => return Ok(()),
+    };
+
+    for mut submodule in repo.submodules()? {
+        update_submodule(parent_remote, &mut submodule, &repo)?;
+
+        let sub_path = working_dir.join(submodule.path());
+        if sub_path.exists() {
+            let sub_url = resolve_submodule_url(parent_remote, &submodule)?;
+            update_submodules(&sub_url, &sub_path, seen)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn update_submodule(
+    parent_remote: &GitRemote,
+    submodule: &mut Submodule<'_>,
+    parent_repo: &Git2Repository,
+) -> Result<()> {
+    let path = submodule.path().to_path_buf();
+    trace!("updating submodule `{}`", path.display());
+
+    submodule.init(false)?;
+
+    let remote = resolve_submodule_url(parent_remote, submodule)?;
+    let target = submodule
+        .workdir_id()
+        .or_else(|| submodule.head_id())
+        .ok_or_else(|| eyre!("submodule `{}` has no recorded commit", path.display()))?;
+
+    let dest = parent_repo.path().join("modules").join(&path);
+    let (database, oid) = remote.checkout(&dest, &GitReference::DefaultBranch, Some(target))?;
+    let checkout_dir = parent_repo.workdir().unwrap_or_else(|| parent_repo.path()).join(&path);
+    // Nested submodules are handled by the caller's recursive walk, so don't recurse here too.
+    database.copy_to(oid, &checkout_dir, false)?;
+
+    Ok(())
+}
+
+/// Resolves a submodule's configured url, which may be relative (e.g. `../lib/foo`), against
+/// its parent's remote.
+fn resolve_submodule_url(parent_remote: &GitRemote, submodule: &Submodule<'_>) -> Result<GitRemote> {
+    let raw_url =
+        submodule.url().ok_or_else(|| eyre!("submodule has no url configured"))?;
+
+    let url = match Url::parse(raw_url) {
+        Ok(url) => url,
+        Err(_) => parent_remote
+            .url()
+            .join(raw_url)
+            .with_context(|| format!("failed to resolve relative submodule url `{raw_url}`"))?,
+    };
+
+    Ok(GitRemote::new(url).with_auth(parent_remote.auth.clone()))
+}
+
+/// Fetches `refspec` from `url` into `repo`, authenticating with `auth` if configured.
+///
+/// Repos with no credentials configured fall back cleanly to anonymous access, so public
+/// repositories keep working exactly as before.
+fn fetch(repo: &Git2Repository, url: &str, refspec: &str, auth: &GitAuth) -> Result<()> {
+    debug!("fetching `{refspec}` from `{url}`");
+
+    let mut remote = repo
+        .find_remote("origin")
+        .or_else(|_| repo.remote_anonymous(url))?;
+
+    let credentials = auth.resolve()?;
+    let mut callbacks = git2::RemoteCallbacks::new();
+    if let Some((username, password)) = credentials {
+        callbacks.credentials(move |_url, _username_from_url, _allowed| {
+            git2::Cred::userpass_plaintext(&username, &password)
+        });
+    }
+
+    let mut opts = git2::FetchOptions::new();
+    opts.remote_callbacks(callbacks);
+    remote.fetch(&[refspec], Some(&mut opts), None)?;
+
+    Ok(())
+}
+
+/// Which VCS backend a [`Repository`](crate::Repository) is checked out with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Backend {
+    /// A git repository, checked out via `git2`.
+    Git,
+    /// A mercurial repository, checked out via the `hg` CLI.
+    Mercurial,
+    /// An explicitly unrecognized backend, kept around verbatim so callers can surface a
+    /// clearer error rather than silently falling back to git.
+    Unknown(String),
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Git
+    }
+}
+
+impl Backend {
+    /// Attempts to detect the backend from a clone url, e.g. a `hg+https://` scheme. Defaults
+    /// to [`Backend::Git`] when nothing matches, since that's the overwhelmingly common case.
+    pub fn detect(url: &Url) -> Self {
+        match url.scheme() {
+            "hg" => Backend::Mercurial,
+            scheme if scheme.starts_with("hg+") => Backend::Mercurial,
+            _ => Backend::Git,
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::Git => write!(f, "git"),
+            Backend::Mercurial => write!(f, "mercurial"),
+            Backend::Unknown(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Checks out a mercurial repository via the `hg` CLI: clones (or pulls into an existing clone)
+/// at `db_path`, then updates a working copy at `dest` to the resolved revision.
+pub fn hg_checkout(url: &Url, rev: &GitReference, db_path: &Path, dest: &Path) -> Result<()> {
+    if db_path.join(".hg").exists() {
+        run_hg(db_path, ["pull"])?;
+    } else {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        run_hg(Path::new("."), ["clone", "--noupdate", url.as_str(), &db_path.display().to_string()])?;
+    }
+
+    let _ = std::fs::remove_dir_all(dest);
+    run_hg(Path::new("."), ["clone", "-U", &db_path.display().to_string(), &dest.display().to_string()])?;
+
+    let mut args = vec!["update".to_string()];
+    if let Some(rev) = hg_rev_arg(rev) {
+        args.push("-r".to_string());
+        args.push(rev);
+    }
+    run_hg(dest, args)?;
+
+    Ok(())
+}
+
+/// Resolves `rev` against a remote mercurial repository to its changeset id (the short hash
+/// `hg identify --id` prints), without cloning.
+pub fn hg_resolve(url: &Url, rev: &GitReference) -> Result<String> {
+    let mut args = vec!["identify".to_string(), "--id".to_string(), url.to_string()];
+    if let Some(rev) = hg_rev_arg(rev) {
+        args.push("-r".to_string());
+        args.push(rev);
+    }
+
+    let output = std::process::Command::new("hg")
+        .args(&args)
+        .output()
+        .context("failed to execute `hg`; is mercurial installed?")?;
+    eyre::ensure!(output.status.success(), "`hg identify` exited with {}", output.status);
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Maps a [`GitReference`] onto the revset `hg` expects for `-r`, reusing the same
+/// branch/tag/rev vocabulary across backends. There's no mercurial equivalent of "whatever the
+/// default branch currently is" as a named rev, so `DefaultBranch` omits `-r` entirely and lets
+/// `hg` resolve to its own default (the tip of the current branch).
+fn hg_rev_arg(rev: &GitReference) -> Option<String> {
+    match rev {
+        GitReference::Branch(s) | GitReference::Tag(s) | GitReference::Rev(s) => Some(s.clone()),
+        GitReference::DefaultBranch => None,
+    }
+}
+
+fn run_hg<I, S>(dir: &Path, args: I) -> Result<()>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<std::ffi::OsStr>,
+{
+    let status = std::process::Command::new("hg")
+        .current_dir(dir)
+        .args(args)
+        .status()
+        .context("failed to execute `hg`; is mercurial installed?")?;
+    eyre::ensure!(status.success(), "`hg` exited with {status}");
+    Ok(())
+}
+
+/// Which forge (git hosting provider) a shorthand [`Repository`](crate::Repository) spec
+/// resolves to.
+///
+/// Stored on `Repository` so it can later inform auth defaults and API-based default-branch
+/// lookups, even though today it's only used to build the clone url.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    /// A recognized-but-unsupported forge prefix, kept around verbatim.
+    Unknown(String),
+}
+
+impl Forge {
+    fn from_prefix(prefix: &str) -> Self {
+        match prefix {
+            "github" => Forge::GitHub,
+            "gitlab" => Forge::GitLab,
+            other => Forge::Unknown(other.to_string()),
+        }
+    }
+
+    fn base_url(&self) -> &str {
+        match self {
+            Forge::GitHub => "https://github.com",
+            Forge::GitLab => "https://gitlab.com",
+            Forge::Unknown(_) => "https://github.com",
+        }
+    }
+}
+
+impl fmt::Display for Forge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Forge::GitHub => write!(f, "github"),
+            Forge::GitLab => write!(f, "gitlab"),
+            Forge::Unknown(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Parses a forge shorthand repo spec such as `github:aave/aave-v3-core`, `gitlab:org/repo`, or
+/// a bare `owner/repo` (which defaults to GitHub), resolving it into the detected [`Forge`]
+/// alongside the clone [`Url`] it points at.
+///
+/// Returns `None` if `spec` doesn't look like a shorthand at all, so callers can fall back to
+/// treating it as a local path. In particular this rejects:
+/// - prefixes that aren't a recognized forge name, so a Windows absolute path like
+///   `C:\Users\foo\project` (prefix `C`) isn't mistaken for a shorthand
+/// - bare `owner/repo`-shaped specs that are actually an existing local path, e.g. a vendored
+///   submodule checked out at `lib/forge-std`
+pub fn parse_shorthand(spec: &str) -> Option<(Forge, Url)> {
+    let (forge, owner_repo) = match spec.split_once(':') {
+        Some((prefix, rest)) if is_forge_prefix(prefix) => (Forge::from_prefix(prefix), rest),
+        Some(_) => return None,
+        None if is_owner_repo(spec) && !Path::new(spec).exists() => (Forge::GitHub, spec),
+        None => return None,
+    };
+
+    let url = Url::parse(&format!("{}/{owner_repo}", forge.base_url())).ok()?;
+    Some((forge, url))
+}
+
+fn is_forge_prefix(s: &str) -> bool {
+    matches!(s, "github" | "gitlab")
+}
+
+fn is_owner_repo(s: &str) -> bool {
+    let Some((owner, repo)) = s.split_once('/') else { return false };
+    !owner.is_empty()
+        && !repo.is_empty()
+        && !repo.contains('/')
+        && owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && repo.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a small, local (non-bare) git repo with a single commit on its default branch,
+    /// so it can stand in for a remote and be fetched from over a `file://` url without any
+    /// network access.
+    fn fixture_origin() -> (tempfile::TempDir, Oid) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Git2Repository::init(dir.path()).unwrap();
+
+        std::fs::write(dir.path().join("README.md"), "hello\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+
+        let sig = git2::Signature::now("test", "test@example.com").unwrap();
+        let commit_id = repo.commit(Some("HEAD"), &sig, &sig, "initial commit", &tree, &[]).unwrap();
+
+        (dir, commit_id)
+    }
+
+    #[test]
+    fn checkout_links_worktree_to_fetched_objects() {
+        let (origin, commit_id) = fixture_origin();
+        let origin_url = Url::from_file_path(origin.path()).unwrap();
+
+        let workdir = tempfile::tempdir().unwrap();
+        let remote = GitRemote::new(origin_url);
+
+        let (database, oid) = remote
+            .checkout(&workdir.path().join("db"), &GitReference::DefaultBranch, None)
+            .unwrap();
+        assert_eq!(oid, commit_id);
+
+        let dest = workdir.path().join("checkout");
+        database.copy_to(oid, &dest, false).unwrap();
+
+        assert_eq!(std::fs::read_to_string(dest.join("README.md")).unwrap(), "hello\n");
+    }
+
+    #[test]
+    fn parse_shorthand_resolves_recognized_forge_prefixes() {
+        let (forge, url) = parse_shorthand("github:org/repo").unwrap();
+        assert_eq!(forge, Forge::GitHub);
+        assert_eq!(url.as_str(), "https://github.com/org/repo");
+
+        let (forge, url) = parse_shorthand("gitlab:org/repo").unwrap();
+        assert_eq!(forge, Forge::GitLab);
+        assert_eq!(url.as_str(), "https://gitlab.com/org/repo");
+    }
+
+    #[test]
+    fn parse_shorthand_resolves_bare_owner_repo_to_github() {
+        let (forge, url) = parse_shorthand("org/repo").unwrap();
+        assert_eq!(forge, Forge::GitHub);
+        assert_eq!(url.as_str(), "https://github.com/org/repo");
+    }
+
+    #[test]
+    fn parse_shorthand_rejects_unrecognized_prefixes() {
+        // `C` isn't a recognized forge name, so a Windows absolute path must not be mistaken
+        // for `prefix:rest` shorthand.
+        assert_eq!(parse_shorthand(r"C:\Users\foo\project"), None);
+    }
+
+    #[test]
+    fn parse_shorthand_rejects_existing_local_paths() {
+        // `is_owner_repo` only matches truly relative two-segment specs (an absolute path can
+        // never split into a non-empty `owner`), so exercising the existence check means
+        // resolving "lib/forge-std" relative to a cwd where that directory actually exists -
+        // mirroring the vendored-submodule layout this is meant to protect.
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("lib/forge-std")).unwrap();
+
+        let original_cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(dir.path()).unwrap();
+        let result = parse_shorthand("lib/forge-std");
+        std::env::set_current_dir(original_cwd).unwrap();
+
+        assert_eq!(result, None);
+    }
+}